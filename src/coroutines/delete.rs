@@ -1,19 +1,34 @@
+use alloc::format;
+
 use log::trace;
 
 use crate::{Entry, Io};
 
 /// The I/O-free coroutine for deleting a keyring entry.
+///
+/// The coroutine resolves to `true` if the entry was deleted, or
+/// `false` if there was nothing to delete. Use [`Delete::must_exist`]
+/// to treat the latter as an error instead.
 #[derive(Clone, Debug)]
 pub struct Delete {
     entry: Option<Entry>,
+    must_exist: bool,
 }
 
 impl Delete {
     pub fn new(entry: Entry) -> Self {
-        Self { entry: Some(entry) }
+        Self {
+            entry: Some(entry),
+            must_exist: false,
+        }
+    }
+
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.must_exist = must_exist;
+        self
     }
 
-    pub fn resume(&mut self, arg: Option<Io>) -> Result<(), Io> {
+    pub fn resume(&mut self, arg: Option<Io>) -> Result<bool, Io> {
         let Some(arg) = arg else {
             let Some(entry) = self.entry.take() else {
                 return Err(Io::err("Entry not ready"));
@@ -23,12 +38,53 @@ impl Delete {
             return Err(Io::Delete(Err(entry)));
         };
 
-        let Io::Delete(Ok(())) = arg else {
+        let Io::Delete(Ok(deleted)) = arg else {
             let err = format!("Expected delete output, got {arg:?}");
             return Err(Io::err(err));
         };
 
+        if self.must_exist && !deleted {
+            return Err(Io::err("Entry not found"));
+        }
+
         trace!("resume after deleting secret from keyring entry");
-        Ok(())
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Entry, Io};
+
+    use super::Delete;
+
+    #[test]
+    fn delete_missing_entry() {
+        let entry = Entry::new("name");
+        let mut delete = Delete::new(entry);
+
+        let Err(Io::Delete(Err(_))) = delete.resume(None) else {
+            panic!("delete should emit I/O");
+        };
+
+        let arg = Some(Io::Delete(Ok(false)));
+        let Ok(false) = delete.resume(arg) else {
+            panic!("missing entry should not be deleted");
+        };
+    }
+
+    #[test]
+    fn delete_missing_entry_must_exist() {
+        let entry = Entry::new("name");
+        let mut delete = Delete::new(entry).must_exist(true);
+
+        let Err(Io::Delete(Err(_))) = delete.resume(None) else {
+            panic!("delete should emit I/O");
+        };
+
+        let arg = Some(Io::Delete(Ok(false)));
+        let Err(Io::Error(_)) = delete.resume(arg) else {
+            panic!("missing entry should be an error");
+        };
     }
 }
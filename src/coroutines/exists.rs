@@ -0,0 +1,69 @@
+use alloc::format;
+
+use log::trace;
+
+use crate::{Entry, Io};
+
+/// The I/O-free coroutine for checking whether a keyring entry
+/// exists.
+///
+/// The coroutine resolves to `true` if the entry exists, `false`
+/// otherwise. Any other failure (locked keyring, unavailable backend
+/// etc.) is reported by the runtime as an error rather than as
+/// `false`.
+///
+/// The secret is not returned, but it may still be read by the
+/// runtime: see `runtimes::std::exists` for the standard runtime.
+#[derive(Clone, Debug)]
+pub struct Exists {
+    entry: Option<Entry>,
+}
+
+impl Exists {
+    pub fn new(entry: Entry) -> Self {
+        Self { entry: Some(entry) }
+    }
+
+    pub fn resume(&mut self, arg: Option<Io>) -> Result<bool, Io> {
+        let Some(arg) = arg else {
+            let Some(entry) = self.entry.take() else {
+                return Err(Io::err("Entry not ready"));
+            };
+
+            trace!("break: need I/O to check keyring entry existence");
+            return Err(Io::Exists(Err(entry)));
+        };
+
+        let Io::Exists(Ok(exists)) = arg else {
+            let err = format!("Expected exists output, got {arg:?}");
+            return Err(Io::err(err));
+        };
+
+        trace!("resume after checking keyring entry existence");
+        Ok(exists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Entry, Io};
+
+    use super::Exists;
+
+    #[test]
+    fn exists() {
+        let entry = Entry::new("name");
+        let mut exists = Exists::new(entry.clone());
+
+        let Err(Io::Exists(Err(got))) = exists.resume(None) else {
+            panic!("exists should emit I/O");
+        };
+
+        assert_eq!(entry, got);
+
+        let arg = Some(Io::Exists(Ok(false)));
+        let Ok(false) = exists.resume(arg) else {
+            panic!("missing entry should not exist");
+        };
+    }
+}
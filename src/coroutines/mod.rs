@@ -4,8 +4,10 @@
 //! [`crate::handlers`] in order to continue their progression.
 
 mod delete;
+mod exists;
 mod read;
+mod update;
 mod write;
 
 #[doc(inline)]
-pub use self::{delete::Delete, read::Read, write::Write};
+pub use self::{delete::Delete, exists::Exists, read::Read, update::Update, write::Write};
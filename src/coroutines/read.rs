@@ -1,3 +1,5 @@
+use alloc::format;
+
 use log::trace;
 use secrecy::SecretString;
 
@@ -0,0 +1,183 @@
+use alloc::format;
+use core::mem;
+
+use log::trace;
+use secrecy::SecretString;
+
+use crate::{
+    coroutines::{write::check_secret, Write},
+    Entry, Io,
+};
+
+/// The I/O-free coroutine for updating an existing keyring entry
+/// secret.
+///
+/// Unlike [`Write`], which creates the entry if needed, this
+/// coroutine first checks that the entry exists using
+/// [`Io::Exists`]. If it does not, the coroutine fails and the entry
+/// is left untouched. Depending on the runtime and the platform
+/// store, this check may read the current secret, see [`Exists`].
+///
+/// Empty secrets and secrets longer than [`Write::DEFAULT_MAX_LEN`]
+/// bytes are rejected by default, see [`Update::allow_empty`] and
+/// [`Update::max_len`].
+///
+/// [`Exists`]: super::Exists
+#[derive(Clone, Debug)]
+pub struct Update {
+    state: State,
+    allow_empty: bool,
+    max_len: usize,
+}
+
+#[derive(Clone, Debug)]
+enum State {
+    Init(Entry, SecretString),
+    CheckingExistence(Entry, SecretString),
+    Writing,
+    Done,
+}
+
+impl Update {
+    pub fn new(entry: Entry, secret: impl Into<SecretString>) -> Self {
+        Self {
+            state: State::Init(entry, secret.into()),
+            allow_empty: false,
+            max_len: Write::DEFAULT_MAX_LEN,
+        }
+    }
+
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    pub fn resume(&mut self, arg: Option<Io>) -> Result<(), Io> {
+        let state = mem::replace(&mut self.state, State::Done);
+
+        let Some(arg) = arg else {
+            let State::Init(entry, secret) = state else {
+                self.state = state;
+                return Err(Io::err("Entry and secret not ready"));
+            };
+
+            check_secret(&secret, self.allow_empty, self.max_len)?;
+            self.state = State::CheckingExistence(entry.clone(), secret);
+
+            trace!("break: need I/O to check keyring entry existence");
+            return Err(Io::Exists(Err(entry)));
+        };
+
+        match (state, arg) {
+            (State::CheckingExistence(..), Io::Exists(Ok(false))) => {
+                trace!("resume after checking keyring entry existence");
+                Err(Io::err("Entry not found"))
+            }
+            (State::CheckingExistence(entry, secret), Io::Exists(Ok(true))) => {
+                self.state = State::Writing;
+
+                trace!("resume after checking keyring entry existence");
+                trace!("break: need I/O to write secret into keyring entry");
+                Err(Io::Write(Err((entry, secret))))
+            }
+            (State::Writing, Io::Write(Ok(()))) => {
+                trace!("resume after writing secret into keyring entry");
+                Ok(())
+            }
+            (state @ State::CheckingExistence(..), arg) => {
+                self.state = state;
+                let err = format!("Expected exists output, got {arg:?}");
+                Err(Io::err(err))
+            }
+            (state @ State::Writing, arg) => {
+                self.state = state;
+                let err = format!("Expected write output, got {arg:?}");
+                Err(Io::err(err))
+            }
+            (state, arg) => {
+                self.state = state;
+                let err = format!("Unexpected output, got {arg:?}");
+                Err(Io::err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Entry, Io};
+
+    use super::Update;
+
+    #[test]
+    fn update() {
+        let entry = Entry::new("name");
+        let mut update = Update::new(entry.clone(), "secret");
+
+        let Err(Io::Exists(Err(got))) = update.resume(None) else {
+            panic!("update should check the entry existence first");
+        };
+
+        assert_eq!(entry, got);
+
+        let arg = Some(Io::Exists(Ok(true)));
+        let Err(Io::Write(Err((got, _)))) = update.resume(arg) else {
+            panic!("update should write the entry once it exists");
+        };
+
+        assert_eq!(entry, got);
+
+        let arg = Some(Io::Write(Ok(())));
+        assert!(update.resume(arg).is_ok());
+    }
+
+    #[test]
+    fn update_without_existence_check() {
+        let entry = Entry::new("name");
+        let mut update = Update::new(entry, "secret");
+
+        let Err(Io::Exists(Err(_))) = update.resume(None) else {
+            panic!("update should check the entry existence first");
+        };
+
+        let arg = Some(Io::Write(Ok(())));
+        let Err(Io::Error(_)) = update.resume(arg) else {
+            panic!("update should not write before checking existence");
+        };
+
+        let arg = Some(Io::Write(Ok(())));
+        let Err(Io::Error(_)) = update.resume(arg) else {
+            panic!("update should still not write before checking existence");
+        };
+    }
+
+    #[test]
+    fn update_missing_entry() {
+        let entry = Entry::new("name");
+        let mut update = Update::new(entry, "secret");
+
+        let Err(Io::Exists(Err(_))) = update.resume(None) else {
+            panic!("update should check the entry existence first");
+        };
+
+        let arg = Some(Io::Exists(Ok(false)));
+        let Err(Io::Error(_)) = update.resume(arg) else {
+            panic!("missing entry should not be updated");
+        };
+
+        let arg = Some(Io::Exists(Ok(true)));
+        let Err(Io::Error(_)) = update.resume(arg) else {
+            panic!("update should end once the entry is not found");
+        };
+
+        let arg = Some(Io::Write(Ok(())));
+        let Err(Io::Error(_)) = update.resume(arg) else {
+            panic!("update should not succeed once the entry is not found");
+        };
+    }
+}
@@ -1,18 +1,51 @@
+use alloc::format;
+
 use log::trace;
-use secrecy::SecretString;
+#[cfg(debug_assertions)]
+use log::warn;
+use secrecy::{ExposeSecret, SecretString};
 
 use crate::{Entry, Io};
 
 /// The I/O-free coroutine for saving a keyring entry secret.
+///
+/// Empty secrets are rejected by default, since an accidentally
+/// empty password is almost always a bug. Use
+/// [`Write::allow_empty`] to store them anyway.
+///
+/// Secrets longer than [`Write::DEFAULT_MAX_LEN`] bytes are rejected
+/// as well, see [`Write::max_len`].
 #[derive(Clone, Debug)]
 pub struct Write {
     secret: Option<(Entry, SecretString)>,
+    allow_empty: bool,
+    max_len: usize,
 }
 
 impl Write {
+    /// The default maximum length, in bytes, of a secret.
+    ///
+    /// Platform stores have their own limits, sometimes lower (for
+    /// example, Windows credentials are capped at 2560 bytes).
+    pub const DEFAULT_MAX_LEN: usize = 64 * 1024;
+
     pub fn new(entry: Entry, secret: impl Into<SecretString>) -> Self {
         let secret = Some((entry, secret.into()));
-        Self { secret }
+        Self {
+            secret,
+            allow_empty: false,
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
+    }
+
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
     }
 
     pub fn resume(&mut self, arg: Option<Io>) -> Result<(), Io> {
@@ -21,6 +54,8 @@ impl Write {
                 return Err(Io::err("Entry and secret not ready"));
             };
 
+            check_secret(&secret.1, self.allow_empty, self.max_len)?;
+
             trace!("break: need I/O to write secret into keyring entry");
             return Err(Io::Write(Err(secret)));
         };
@@ -33,4 +68,112 @@ impl Write {
         trace!("resume after writing secret into keyring entry");
         Ok(())
     }
+
+    /// Warns if the secret was never handed to a runtime, which
+    /// usually means the coroutine was not driven. Returns `true` if
+    /// the warning was emitted.
+    #[cfg(debug_assertions)]
+    fn warn_if_unwritten(&self) -> bool {
+        if self.secret.is_none() {
+            return false;
+        }
+
+        warn!("write coroutine dropped with an unwritten secret");
+        true
+    }
+}
+
+/// Checks a secret before it is handed to a runtime.
+///
+/// Shared by the coroutines that emit [`Io::Write`].
+pub(crate) fn check_secret(
+    secret: &SecretString,
+    allow_empty: bool,
+    max_len: usize,
+) -> Result<(), Io> {
+    let len = secret.expose_secret().len();
+
+    if !allow_empty && len == 0 {
+        return Err(Io::err("Secret cannot be empty"));
+    }
+
+    if len > max_len {
+        let err = format!("Secret too large ({len} > {max_len} bytes)");
+        return Err(Io::err(err));
+    }
+
+    Ok(())
+}
+
+#[cfg(debug_assertions)]
+impl Drop for Write {
+    fn drop(&mut self) {
+        self.warn_if_unwritten();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use secrecy::SecretString;
+
+    use crate::{Entry, Io};
+
+    use super::Write;
+
+    #[test]
+    fn reject_empty_secret() {
+        let entry = Entry::new("name");
+        let mut write = Write::new(entry, "");
+
+        let Err(Io::Error(_)) = write.resume(None) else {
+            panic!("empty secret should be rejected");
+        };
+    }
+
+    #[test]
+    fn allow_empty_secret() {
+        let entry = Entry::new("name");
+        let mut write = Write::new(entry, "").allow_empty(true);
+
+        let Err(Io::Write(Err(_))) = write.resume(None) else {
+            panic!("empty secret should be allowed");
+        };
+
+        let arg = Some(Io::Write(Ok(())));
+        assert!(write.resume(arg).is_ok());
+    }
+
+    #[test]
+    fn check_secret() {
+        let max = Write::DEFAULT_MAX_LEN;
+
+        let secret = SecretString::from("a".repeat(max));
+        assert!(super::check_secret(&secret, false, max).is_ok());
+
+        let secret = SecretString::from("a".repeat(max + 1));
+        let Err(Io::Error(_)) = super::check_secret(&secret, false, max) else {
+            panic!("secret above max length should be rejected");
+        };
+
+        let secret = SecretString::from("");
+        assert!(super::check_secret(&secret, true, max).is_ok());
+
+        let Err(Io::Error(_)) = super::check_secret(&secret, false, max) else {
+            panic!("empty secret should be rejected");
+        };
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn warn_if_unwritten() {
+        let entry = Entry::new("name");
+        let mut write = Write::new(entry, "secret");
+        assert!(write.warn_if_unwritten());
+
+        let Err(Io::Write(Err(_))) = write.resume(None) else {
+            panic!("write should emit I/O");
+        };
+
+        assert!(!write.warn_if_unwritten());
+    }
 }
@@ -1,3 +1,11 @@
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "std")]
+use log::warn;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Entry {
     pub name: String,
@@ -5,23 +13,260 @@ pub struct Entry {
 }
 
 impl Entry {
+    /// The service used by [`Entry::new`] when none is given.
+    ///
+    /// This is the name of this crate, not the name of the crate
+    /// using it: applications should always set their own service
+    /// with [`Entry::service`].
+    pub const DEFAULT_SERVICE: &'static str = env!("CARGO_CRATE_NAME");
+
+    /// The maximum length, in bytes, of an entry service or name.
+    pub const MAX_LEN: usize = 1024;
+
+    /// Creates an entry with the given name, using
+    /// [`Entry::DEFAULT_SERVICE`] as service.
     pub fn new(name: impl ToString) -> Self {
         Self {
             name: name.to_string(),
-            service: env!("CARGO_CRATE_NAME").to_string(),
+            service: Self::DEFAULT_SERVICE.to_string(),
         }
     }
 
+    /// Creates an entry from an explicit service and name.
+    pub fn from_parts(service: impl ToString, name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            service: service.to_string(),
+        }
+    }
+
+    /// Parses an entry from its `service:name` form.
+    ///
+    /// See the [`FromStr`] implementation for the splitting rule.
+    pub fn parse(combined: &str) -> Result<Self, ParseEntryError> {
+        combined.parse()
+    }
+
     pub fn service(mut self, service: impl ToString) -> Self {
         self.service = service.to_string();
         self
     }
+
+    /// Checks that the entry can safely be sent to a keyring.
+    ///
+    /// The service and the name must be non-empty, must not contain
+    /// NUL bytes and must not exceed [`Entry::MAX_LEN`] bytes.
+    pub fn validate(&self) -> Result<(), InvalidEntryError> {
+        if self.service.is_empty() {
+            return Err(InvalidEntryError::EmptyService);
+        }
+
+        if self.name.is_empty() {
+            return Err(InvalidEntryError::EmptyName);
+        }
+
+        if self.service.contains('\0') || self.name.contains('\0') {
+            return Err(InvalidEntryError::NulByte);
+        }
+
+        let len = self.service.len().max(self.name.len());
+
+        if len > Self::MAX_LEN {
+            let max = Self::MAX_LEN;
+            return Err(InvalidEntryError::TooLong { len, max });
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays the entry as `service:name`.
+///
+/// The output parses back into the same entry with [`FromStr`],
+/// unless the service contains a colon: the first colon is always
+/// taken as the separator.
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.service, self.name)
+    }
+}
+
+/// Parses an entry from its `service:name` form.
+///
+/// The first colon separates the service from the name, which means
+/// the name can contain colons but the service cannot.
+impl FromStr for Entry {
+    type Err = ParseEntryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((service, name)) = s.split_once(':') else {
+            return Err(ParseEntryError::MissingSeparator);
+        };
+
+        if service.is_empty() {
+            return Err(ParseEntryError::EmptyService);
+        }
+
+        if name.is_empty() {
+            return Err(ParseEntryError::EmptyName);
+        }
+
+        Ok(Entry::from_parts(service, name))
+    }
+}
+
+/// The error returned when parsing an [`Entry`] from a string fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseEntryError {
+    MissingSeparator,
+    EmptyService,
+    EmptyName,
+}
+
+impl fmt::Display for ParseEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => f.write_str("missing colon between entry service and name"),
+            Self::EmptyService => f.write_str("entry service cannot be empty"),
+            Self::EmptyName => f.write_str("entry name cannot be empty"),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEntryError {}
+
+/// The error returned by [`Entry::validate`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidEntryError {
+    EmptyService,
+    EmptyName,
+    NulByte,
+    TooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for InvalidEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyService => f.write_str("entry service cannot be empty"),
+            Self::EmptyName => f.write_str("entry name cannot be empty"),
+            Self::NulByte => f.write_str("entry service and name cannot contain NUL bytes"),
+            Self::TooLong { len, max } => {
+                write!(f, "entry service or name too long ({len} > {max} bytes)")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidEntryError {}
+
+#[cfg(feature = "std")]
 impl TryFrom<Entry> for keyring::Entry {
     type Error = keyring::Error;
 
     fn try_from(entry: Entry) -> keyring::Result<Self> {
+        static DEFAULT_SERVICE_WARNED: AtomicBool = AtomicBool::new(false);
+
+        if entry.service == Entry::DEFAULT_SERVICE
+            && !DEFAULT_SERVICE_WARNED.swap(true, Ordering::Relaxed)
+        {
+            let service = Entry::DEFAULT_SERVICE;
+            warn!("keyring entry uses the default service {service}, consider setting your own");
+        }
+
         keyring::Entry::new(&entry.service, &entry.name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{Entry, InvalidEntryError, ParseEntryError};
+
+    #[test]
+    fn display() {
+        let entry = Entry::new("name").service("service");
+        assert_eq!("service:name", entry.to_string());
+    }
+
+    #[test]
+    fn parse() {
+        let expected = Entry::new("name").service("service");
+        assert_eq!(Ok(expected), "service:name".parse());
+
+        let expected = Entry::new("name:with:colons").service("service");
+        assert_eq!(Ok(expected), "service:name:with:colons".parse());
+
+        let err = ParseEntryError::MissingSeparator;
+        assert_eq!(Err::<Entry, _>(err), "name".parse());
+
+        let err = ParseEntryError::EmptyService;
+        assert_eq!(Err::<Entry, _>(err), ":name".parse());
+
+        let err = ParseEntryError::EmptyName;
+        assert_eq!(Err::<Entry, _>(err), "service:".parse());
+    }
+
+    #[test]
+    fn parse_splits_on_first_colon() {
+        let expected = Entry::from_parts("imap", "user@example.org:993");
+        assert_eq!(Ok(expected), Entry::parse("imap:user@example.org:993"));
+
+        let expected = Entry::from_parts("service", ":name");
+        assert_eq!(Ok(expected), Entry::parse("service::name"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let entry = Entry::new("user@example.org:imap").service("service");
+        assert_eq!(Ok(entry.clone()), entry.to_string().parse());
+    }
+
+    #[test]
+    fn round_trip_service_with_colon() {
+        let entry = Entry::new("name").service("ser:vice");
+        assert_eq!("ser:vice:name", entry.to_string());
+
+        let expected = Entry::new("vice:name").service("ser");
+        assert_eq!(Ok(expected), entry.to_string().parse());
+    }
+
+    #[test]
+    fn round_trip_unicode() {
+        let entry = Entry::new("utilisateur@exemple.côte").service("サービス");
+        assert_eq!("サービス:utilisateur@exemple.côte", entry.to_string());
+        assert_eq!(Ok(entry.clone()), entry.to_string().parse());
+
+        let entry = Entry::new("名前:ユーザー").service("é");
+        assert_eq!(Ok(entry.clone()), entry.to_string().parse());
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Ok(()), Entry::new("name").service("service").validate());
+
+        let err = InvalidEntryError::EmptyService;
+        assert_eq!(Err(err), Entry::new("name").service("").validate());
+
+        let err = InvalidEntryError::EmptyName;
+        assert_eq!(Err(err), Entry::new("").service("service").validate());
+
+        let err = InvalidEntryError::NulByte;
+        assert_eq!(Err(err.clone()), Entry::new("na\0me").validate());
+        assert_eq!(Err(err), Entry::new("name").service("\0").validate());
+
+        let name = "a".repeat(Entry::MAX_LEN);
+        assert_eq!(Ok(()), Entry::new(&name).validate());
+
+        let name = "a".repeat(Entry::MAX_LEN + 1);
+        let err = InvalidEntryError::TooLong {
+            len: Entry::MAX_LEN + 1,
+            max: Entry::MAX_LEN,
+        };
+        assert_eq!(Err(err), Entry::new(name).validate());
+    }
+}
@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{format, string::String};
+use core::fmt;
 
 use secrecy::SecretString;
 
@@ -13,6 +14,7 @@ use crate::Entry;
 /// [coroutines]: crate::coroutines
 /// [runtimes]: crate::runtimes
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum Io {
     /// Generic error related to coroutine progression.
     Error(String),
@@ -24,7 +26,15 @@ pub enum Io {
     Write(Result<(), (Entry, SecretString)>),
 
     /// I/O for deleting a keyring entry.
-    Delete(Result<(), Entry>),
+    ///
+    /// The output tells whether an entry was actually deleted.
+    Delete(Result<bool, Entry>),
+
+    /// I/O for checking whether a keyring entry exists.
+    ///
+    /// The output is the secret-free answer, but whether the runtime
+    /// has to read the secret to produce it depends on the runtime.
+    Exists(Result<bool, Entry>),
 }
 
 impl Io {
@@ -32,4 +42,30 @@ impl Io {
         let msg = format!("Keyring error: {msg}");
         Io::Error(msg)
     }
+
+    /// Returns `true` if the I/O is a generic error.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Io::Error(_))
+    }
+
+    /// Returns `true` if the I/O is related to reading a secret.
+    pub fn is_read(&self) -> bool {
+        matches!(self, Io::Read(_))
+    }
+
+    /// Returns `true` if the I/O is related to saving a secret.
+    pub fn is_write(&self) -> bool {
+        matches!(self, Io::Write(_))
+    }
+
+    /// Returns `true` if the I/O is related to deleting an entry.
+    pub fn is_delete(&self) -> bool {
+        matches!(self, Io::Delete(_))
+    }
+
+    /// Returns `true` if the I/O is related to checking an entry
+    /// existence.
+    pub fn is_exists(&self) -> bool {
+        matches!(self, Io::Exists(_))
+    }
 }
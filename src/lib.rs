@@ -1,5 +1,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod coroutines;
 mod entry;
@@ -9,4 +14,33 @@ pub mod runtimes;
 pub mod serde;
 
 #[doc(inline)]
-pub use self::{entry::Entry, io::Io};
+pub use self::{
+    entry::{Entry, InvalidEntryError, ParseEntryError},
+    io::Io,
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coroutines::{Delete, Exists, Read, Update, Write},
+        Entry, InvalidEntryError, Io, ParseEntryError,
+    };
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_sync() {
+        assert_send_sync::<Entry>();
+        assert_send_sync::<ParseEntryError>();
+        assert_send_sync::<InvalidEntryError>();
+        assert_send_sync::<Io>();
+        assert_send_sync::<Read>();
+        assert_send_sync::<Write>();
+        assert_send_sync::<Update>();
+        assert_send_sync::<Delete>();
+        assert_send_sync::<Exists>();
+
+        #[cfg(feature = "std")]
+        assert_send_sync::<crate::runtimes::std::Error>();
+    }
+}
@@ -1,16 +1,69 @@
 //! Module dedicated to the standard, blocking keyring I/O handler.
 
+use alloc::string::String;
+
 use secrecy::{ExposeSecret, SecretString};
 use thiserror::Error;
 
-use crate::{Entry, Io};
+use crate::{Entry, InvalidEntryError, Io};
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error("{0}")]
     Other(String),
     #[error(transparent)]
     Keyring(#[from] keyring::Error),
+    #[error("Invalid keyring entry: {0}")]
+    InvalidEntry(#[from] InvalidEntryError),
+    #[error("Unexpected keyring I/O: {0:?}")]
+    UnexpectedIo(Io),
+}
+
+impl Error {
+    /// Returns the kind of the error.
+    ///
+    /// Prefer matching on the kind rather than on the error itself:
+    /// new kinds may be added, but existing ones will not change.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Other(_) => ErrorKind::Other,
+            Error::Keyring(_) => ErrorKind::Keyring,
+            Error::InvalidEntry(_) => ErrorKind::InvalidEntry,
+            Error::UnexpectedIo(_) => ErrorKind::UnexpectedIo,
+        }
+    }
+}
+
+/// Converts an I/O emitted by a coroutine into an error.
+///
+/// [`Io::Error`] becomes [`Error::Other`]. Any other variant is an
+/// I/O request that should have been processed by [`handle`]
+/// instead, and becomes [`Error::UnexpectedIo`].
+impl From<Io> for Error {
+    fn from(io: Io) -> Self {
+        match io {
+            Io::Error(err) => Error::Other(err),
+            io => Error::UnexpectedIo(io),
+        }
+    }
+}
+
+/// The kind of a standard, blocking keyring I/O handler [`enum@Error`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Generic error, usually emitted by a coroutine.
+    Other,
+
+    /// Error coming from the underlying keyring.
+    Keyring,
+
+    /// The entry did not pass [`Entry::validate`].
+    InvalidEntry,
+
+    /// An I/O request was converted into an error.
+    UnexpectedIo,
 }
 
 /// The standard, blocking keyring I/O handler.
@@ -20,6 +73,7 @@ pub fn handle(io: Io) -> Result<Io, Error> {
         Io::Read(io) => read(io),
         Io::Write(io) => write(io),
         Io::Delete(io) => delete(io),
+        Io::Exists(io) => exists(io),
     }
 }
 
@@ -35,10 +89,18 @@ pub fn read(input: Result<SecretString, Entry>) -> Result<Io, Error> {
     Ok(Io::Read(Ok(secret)))
 }
 
+/// Saves a keyring entry secret.
+///
+/// The entry is checked with [`Entry::validate`] first. Other
+/// operations skip that check, so that entries created outside of
+/// this crate can still be read and deleted.
 pub fn write(input: Result<(), (Entry, SecretString)>) -> Result<Io, Error> {
     let (entry, secret) = match input {
         Ok(()) => return Ok(Io::Write(Ok(()))),
-        Err((entry, secret)) => (keyring::Entry::try_from(entry)?, secret),
+        Err((entry, secret)) => {
+            entry.validate()?;
+            (keyring::Entry::try_from(entry)?, secret)
+        }
     };
 
     let secret = secret.expose_secret();
@@ -47,13 +109,54 @@ pub fn write(input: Result<(), (Entry, SecretString)>) -> Result<Io, Error> {
     Ok(Io::Write(Ok(())))
 }
 
-pub fn delete(input: Result<(), Entry>) -> Result<Io, Error> {
+pub fn delete(input: Result<bool, Entry>) -> Result<Io, Error> {
     let entry = match input {
         Ok(output) => return Ok(Io::Delete(Ok(output))),
         Err(entry) => keyring::Entry::try_from(entry)?,
     };
 
-    entry.delete_credential()?;
+    let deleted = match entry.delete_credential() {
+        Ok(()) => true,
+        Err(keyring::Error::NoEntry) => false,
+        Err(err) => return Err(err.into()),
+    };
 
-    Ok(Io::Delete(Ok(())))
+    Ok(Io::Delete(Ok(deleted)))
+}
+
+/// Checks whether a keyring entry exists, using
+/// [`keyring::Entry::get_attributes`].
+///
+/// Only the Secret Service and Windows stores answer without reading
+/// the secret. Other stores (macOS and iOS keychains, keyutils etc.)
+/// fall back to fetching it, which may trigger an access prompt.
+pub fn exists(input: Result<bool, Entry>) -> Result<Io, Error> {
+    let entry = match input {
+        Ok(output) => return Ok(Io::Exists(Ok(output))),
+        Err(entry) => keyring::Entry::try_from(entry)?,
+    };
+
+    let exists = match entry.get_attributes() {
+        Ok(_) => true,
+        Err(keyring::Error::NoEntry) => false,
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(Io::Exists(Ok(exists)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Entry, Io};
+
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn error_from_io() {
+        let err = Error::from(Io::err("boom"));
+        assert_eq!(ErrorKind::Other, err.kind());
+
+        let err = Error::from(Io::Read(Err(Entry::new("name"))));
+        assert_eq!(ErrorKind::UnexpectedIo, err.kind());
+    }
 }
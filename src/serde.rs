@@ -1,6 +1,6 @@
 //! Module dedicated to [`serde`] de/serialization of [`Entry`].
 
-use std::fmt;
+use core::fmt;
 
 use serde::{
     de::{Error, Visitor},
@@ -37,6 +37,7 @@ impl<'de> Visitor<'de> for EntryVisitor {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::String;
     use serde::{
         de::value::{Error, StringDeserializer},
         Deserialize,